@@ -0,0 +1,16 @@
+use regex::Regex;
+
+/// 从用户粘贴的抽卡记录查询链接中提取 `authkey` 参数
+///
+/// 链接形如 `...record.wutheringwaves.com/...?...&authkey=xxx&...`，
+/// 只要 `authkey` 参数存在且非空即认为链接有效
+pub fn extract_authkey(url: &str) -> Option<String> {
+    let re = Regex::new(r"authkey=([^&\s]+)").ok()?;
+    let captures = re.captures(url)?;
+    let authkey = captures.get(1)?.as_str();
+    if authkey.is_empty() {
+        None
+    } else {
+        Some(authkey.to_string())
+    }
+}
@@ -0,0 +1,39 @@
+use std::collections::HashMap;
+
+use crate::core::statistics::GachaStatistics;
+
+/// 当前版本各卡池的 UP 物品名单，需要随版本更新同步维护，否则该卡池视为"暂无数据"
+///
+/// 角色/武器活动唤取卡池（1、2）以外的常驻与新手卡池没有 UP 概念，不在此列出。
+/// 尚未填入当期角色前，对应卡池的名单留空，`has_known_up_list` 会据此禁止
+/// 下游把"未分类"误判成"全部歪到常驻"
+fn current_up_item_names() -> HashMap<i32, Vec<&'static str>> {
+    HashMap::from([
+        (1, vec![]),
+        (2, vec![]),
+    ])
+}
+
+/// 当前是否维护了该卡池的 UP 物品名单；名单为空视为没有可用的分类数据
+pub fn has_known_up_list(card_pool_type: i32) -> bool {
+    current_up_item_names()
+        .get(&card_pool_type)
+        .is_some_and(|names| !names.is_empty())
+}
+
+/// 按当期 UP 物品名单为抽卡统计数据中的每个五星物品标注 `is_up`
+///
+/// 这是目前唯一能确定物品是否为 UP 的地方：抽卡接口本身只返回物品名称，
+/// 不包含是否为 UP 的标记，因此只能依赖这份随版本维护的名单来判断。
+/// 名单为空的卡池中所有五星都会被标为非 UP，调用方应配合 `has_known_up_list` 判断是否展示分析结果
+pub fn classify_up_items(gacha_statistics: &mut GachaStatistics) {
+    let up_names = current_up_item_names();
+    for (card_pool_type, data) in gacha_statistics.iter_mut() {
+        let Some(names) = up_names.get(card_pool_type) else {
+            continue;
+        };
+        for item in data.detail.iter_mut() {
+            item.is_up = names.contains(&item.name.as_str());
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use crate::core::banner::has_known_up_list;
+use crate::core::statistics::GachaStatisticsData;
+
+/// 角色活动唤取卡池的 UP 50/50 与保底状态分析
+pub struct FiftyFiftyStats {
+    /// 该卡池当前是否维护了 UP 物品名单；为 false 时其余字段均无意义，
+    /// 调用方不应据此展示胜负或保底状态，以免把"未分类"误报成"全部歪到常驻"
+    pub has_up_data: bool,
+    pub won: i32,
+    pub lost: i32,
+    pub is_guaranteed: bool,
+    pub average_pulls_per_five_star: f64,
+}
+
+/// 根据 `detail` 中各五星物品是否为 UP 物品，估算 50/50 胜负与当前是否处于保底状态
+///
+/// 聚合数据不保留抽取顺序，无法复盘每一抽的胜负，这里只统计能从总数安全推断的部分：
+/// 歪货数量即为已确认的丢失次数，UP 数量超出歪货数量的部分即为已确认的胜利次数。
+/// 是否处于保底状态同样无法精确判断最后一抽的结果，唯一能安全推断的情形是
+/// UP 数量少于歪货数量——此时必然还欠一次保底，其余情形一律视为未保底。
+///
+/// 若该卡池当前没有维护 UP 名单（`has_known_up_list` 为 false），所有五星都会被
+/// `classify_up_items` 标为非 UP，此时不能把 `standard_count` 当作真实的歪货数量，
+/// 否则会对每一位玩家都报出"已歪，下次五星必中UP"，因此直接返回 `has_up_data: false`
+pub fn analyze(card_pool_type: i32, data: &GachaStatisticsData) -> FiftyFiftyStats {
+    if !has_known_up_list(card_pool_type) {
+        return FiftyFiftyStats {
+            has_up_data: false,
+            won: 0,
+            lost: 0,
+            is_guaranteed: false,
+            average_pulls_per_five_star: 0.0,
+        };
+    }
+
+    let up_count: i32 = data.detail.iter().filter(|item| item.is_up).map(|item| item.count).sum();
+    let standard_count: i32 = data.detail.iter().filter(|item| !item.is_up).map(|item| item.count).sum();
+
+    let lost = standard_count;
+    let won = (up_count - standard_count).max(0);
+    let five_star_total = up_count + standard_count;
+
+    FiftyFiftyStats {
+        has_up_data: true,
+        won,
+        lost,
+        is_guaranteed: up_count < standard_count,
+        average_pulls_per_five_star: if five_star_total > 0 {
+            data.total as f64 / five_star_total as f64
+        } else {
+            0.0
+        },
+    }
+}
@@ -0,0 +1,39 @@
+use std::fs::File;
+use std::io::Read;
+use std::path::PathBuf;
+
+use regex::Regex;
+
+/// 定位鸣潮客户端的本地日志/缓存文件
+fn log_file_path() -> PathBuf {
+    let local_app_data = std::env::var("LOCALAPPDATA").unwrap_or_default();
+    PathBuf::from(local_app_data)
+        .join("Wuthering Waves")
+        .join("Wuthering Waves Game")
+        .join("Client")
+        .join("Saved")
+        .join("Logs")
+        .join("Client.log")
+}
+
+/// 从客户端日志中提取最近一次抽卡记录查询链接
+///
+/// 日志在抽卡记录界面打开时会打印请求地址，其中带有 `authkey` 等查询参数，
+/// 用正则从头到尾匹配日志文本中的抽卡记录地址，取最后一次出现的地址
+pub fn find_gacha_url_from_log() -> anyhow::Result<String> {
+    let path = log_file_path();
+
+    // 游戏运行中日志文件可能被独占写入，这里按只读方式尝试打开，失败时给出明确提示
+    let mut file = File::open(&path)
+        .map_err(|err| anyhow::anyhow!("无法读取日志文件（可能游戏正在运行）：{}", err))?;
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .map_err(|err| anyhow::anyhow!("日志文件被占用或无法读取：{}", err))?;
+
+    let re = Regex::new(r"https?://[^\s\"]+gacha[^\s\"]*authkey=[^\s\"&]+[^\s\"]*")?;
+    re.find_iter(&content)
+        .last()
+        .map(|m| m.as_str().to_string())
+        .ok_or_else(|| anyhow::anyhow!("未在日志中找到抽卡记录链接，请先在游戏内打开一次抽卡记录页面"))
+}
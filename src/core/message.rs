@@ -0,0 +1,40 @@
+use std::sync::mpsc::Sender;
+
+/// 展示在 `MainView` 顶部的状态提示
+pub struct Message {
+    pub success: bool,
+    pub message: String,
+}
+
+impl Default for Message {
+    fn default() -> Self {
+        Self {
+            success: true,
+            message: String::new(),
+        }
+    }
+}
+
+/// 后台线程向 UI 线程推送状态提示的发送端封装
+#[derive(Clone)]
+pub struct MessageSender {
+    message_tx: Sender<Message>,
+}
+
+impl MessageSender {
+    pub fn new(message_tx: Sender<Message>) -> Self {
+        Self { message_tx }
+    }
+
+    pub fn send(&self, message: String) {
+        let _ = self.message_tx.send(Message { success: true, message });
+    }
+
+    pub fn success(&self, message: String) {
+        let _ = self.message_tx.send(Message { success: true, message });
+    }
+
+    pub fn failed(&self, message: String) {
+        let _ = self.message_tx.send(Message { success: false, message });
+    }
+}
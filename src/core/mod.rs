@@ -0,0 +1,8 @@
+pub mod auth_url;
+pub mod banner;
+pub mod gacha_analysis;
+pub mod log_scanner;
+pub mod message;
+pub mod probability;
+pub mod statistics;
+pub mod uigf;
@@ -0,0 +1,103 @@
+/// 描述五星概率曲线上的一个拐点：从 `start_pity` 抽开始，基础概率为
+/// `start_chance_percent`，此后每多垫一抽概率再增加 `increment_percent`
+#[derive(Clone, Copy)]
+pub struct ProbabilityPoint {
+    pub start_pity: i32,
+    pub start_chance_percent: f64,
+    pub increment_percent: f64,
+}
+
+/// 单个卡池的五星软保底概率模型
+#[derive(Clone)]
+pub struct ProbabilityModel {
+    pub points: Vec<ProbabilityPoint>,
+    pub hard_pity: i32,
+}
+
+impl ProbabilityModel {
+    /// 展开为每一抽（1..=hard_pity）对应的出金概率（百分比）
+    pub fn probability_percents(&self) -> Vec<f64> {
+        let mut percents = vec![0f64; self.hard_pity as usize + 1];
+        for pity in 1..=self.hard_pity {
+            let mut chance = 0f64;
+            for point in &self.points {
+                if pity >= point.start_pity {
+                    chance = point.start_chance_percent
+                        + (pity - point.start_pity) as f64 * point.increment_percent;
+                }
+            }
+            percents[pity as usize] = chance.min(100.0);
+        }
+        percents
+    }
+
+    /// 从 `pull_count + 1` 抽开始，未来 `k` 抽内出金的累计概率（百分比）
+    pub fn chance_within_next_pulls(&self, pull_count: i32, k: i32) -> f64 {
+        let percents = self.probability_percents();
+        let last_pity = (pull_count + k).min(self.hard_pity);
+
+        let mut miss_probability = 1.0;
+        for pity in (pull_count + 1)..=last_pity {
+            if pity < 1 || pity > self.hard_pity {
+                continue;
+            }
+            let p = percents[pity as usize] / 100.0;
+            miss_probability *= 1.0 - p;
+        }
+
+        if pull_count + k >= self.hard_pity {
+            100.0
+        } else {
+            ((1.0 - miss_probability) * 100.0).min(100.0)
+        }
+    }
+
+    /// 距离硬保底还需要多少抽
+    pub fn pulls_until_guarantee(&self, pull_count: i32) -> i32 {
+        (self.hard_pity - pull_count).max(0)
+    }
+}
+
+/// 鸣潮角色/武器活动唤取的五星软保底模型：1~65 抽基础 0.8%，66 抽起每抽 +7.09%，80 抽硬保底
+pub fn character_event_pool_model() -> ProbabilityModel {
+    ProbabilityModel {
+        points: vec![
+            ProbabilityPoint { start_pity: 1, start_chance_percent: 0.8, increment_percent: 0.0 },
+            ProbabilityPoint { start_pity: 66, start_chance_percent: 0.8, increment_percent: 7.09 },
+        ],
+        hard_pity: 80,
+    }
+}
+
+/// 角色/武器常驻唤取的五星软保底模型：1~69 抽基础 0.8%，70 抽起每抽 +9.92%，80 抽硬保底
+pub fn standard_pool_model() -> ProbabilityModel {
+    ProbabilityModel {
+        points: vec![
+            ProbabilityPoint { start_pity: 1, start_chance_percent: 0.8, increment_percent: 0.0 },
+            ProbabilityPoint { start_pity: 70, start_chance_percent: 0.8, increment_percent: 9.92 },
+        ],
+        hard_pity: 80,
+    }
+}
+
+/// 新手唤取的五星软保底模型：保底抽数远短于常规卡池，1~39 抽基础 2%，40 抽起每抽 +9.8%，50 抽硬保底
+pub fn beginner_pool_model() -> ProbabilityModel {
+    ProbabilityModel {
+        points: vec![
+            ProbabilityPoint { start_pity: 1, start_chance_percent: 2.0, increment_percent: 0.0 },
+            ProbabilityPoint { start_pity: 40, start_chance_percent: 2.0, increment_percent: 9.8 },
+        ],
+        hard_pity: 50,
+    }
+}
+
+/// 根据卡池类型返回对应的概率模型：角色/武器活动唤取（1、2）、角色/武器常驻唤取（3、4）、
+/// 新手相关唤取（5、6、7）的保底机制并不相同，分别使用各自的曲线
+pub fn model_for_card_pool_type(card_pool_type: i32) -> ProbabilityModel {
+    match card_pool_type {
+        1 | 2 => character_event_pool_model(),
+        3 | 4 => standard_pool_model(),
+        5 | 6 | 7 => beginner_pool_model(),
+        _ => character_event_pool_model(),
+    }
+}
@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+use std::collections::hash_map::{Iter, IterMut};
+
+use serde::{Deserialize, Serialize};
+
+/// 单个五星物品的累计抽取情况
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct GachaStatisticsDataItem {
+    pub name: String,
+    pub count: i32,
+    /// 是否为当期 UP 物品，否则为歪到的常驻五星
+    pub is_up: bool,
+    /// 最近一次抽到该物品的时间
+    pub time: String,
+}
+
+/// 单个卡池的抽卡统计数据
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct GachaStatisticsData {
+    pub total: i32,
+    pub pull_count: i32,
+    pub three_count: i32,
+    pub four_count: i32,
+    pub five_count: i32,
+    pub detail: Vec<GachaStatisticsDataItem>,
+}
+
+/// 所有卡池的抽卡统计数据，以卡池类型为键
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct GachaStatistics(HashMap<i32, GachaStatisticsData>);
+
+impl GachaStatistics {
+    pub fn new() -> Self {
+        Self(HashMap::new())
+    }
+
+    pub fn iter(&self) -> Iter<'_, i32, GachaStatisticsData> {
+        self.0.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, i32, GachaStatisticsData> {
+        self.0.iter_mut()
+    }
+
+    /// 将另一份统计数据合并进来，用于导入跨设备记录时与当前已加载的数据叠加，
+    /// 而不是整份覆盖。由于聚合计数无法去重，汇总类字段取两者较大值，
+    /// 五星明细按物品名合并（同名物品取较大抽取次数）
+    ///
+    /// `pull_count` 是距离上次五星的垫抽数，每次出金都会回落，并非只增不减，
+    /// 不能像其它汇总字段一样取较大值——否则导入一份抽数更高的旧备份会把刚
+    /// 回落的保底读数覆盖回去。已有该卡池数据时保留当前值，下次拉取会自然刷新；
+    /// 仅在该卡池是首次导入（本地尚无记录）时才采用导入数据里的垫抽数
+    pub fn merge(&mut self, other: GachaStatistics) {
+        for (card_pool_type, other_data) in other.0 {
+            let is_new_pool = !self.0.contains_key(&card_pool_type);
+            let data = self.0.entry(card_pool_type).or_insert_with(GachaStatisticsData::default);
+            data.total = data.total.max(other_data.total);
+            if is_new_pool {
+                data.pull_count = other_data.pull_count;
+            }
+            data.three_count = data.three_count.max(other_data.three_count);
+            data.four_count = data.four_count.max(other_data.four_count);
+            data.five_count = data.five_count.max(other_data.five_count);
+
+            for other_item in other_data.detail {
+                match data.detail.iter_mut().find(|item| item.name == other_item.name) {
+                    Some(existing) => {
+                        existing.count = existing.count.max(other_item.count);
+                        existing.is_up = existing.is_up || other_item.is_up;
+                        if existing.time.is_empty() {
+                            existing.time = other_item.time;
+                        }
+                    }
+                    None => data.detail.push(other_item),
+                }
+            }
+        }
+    }
+}
+
+impl From<HashMap<i32, GachaStatisticsData>> for GachaStatistics {
+    fn from(map: HashMap<i32, GachaStatisticsData>) -> Self {
+        Self(map)
+    }
+}
+
+/// 从本地缓存文件中读取上一次拉取的抽卡统计数据
+pub fn gacha_statistics_from_cache() -> anyhow::Result<GachaStatistics> {
+    let content = std::fs::read_to_string(cache_file_path())?;
+    Ok(serde_json::from_str(&content)?)
+}
+
+/// 将最新抽取到的统计数据写入本地缓存文件，加快下次启动时的首屏加载速度
+pub fn save_gacha_statistics_to_cache(gacha_statistics: &GachaStatistics) -> anyhow::Result<()> {
+    let content = serde_json::to_string(gacha_statistics)?;
+    std::fs::write(cache_file_path(), content)?;
+    Ok(())
+}
+
+fn cache_file_path() -> std::path::PathBuf {
+    std::path::PathBuf::from("gacha_statistics_cache.json")
+}
@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::statistics::{GachaStatistics, GachaStatisticsData, GachaStatisticsDataItem};
+
+/// 导出文件中的单条抽卡记录，字段命名参考 UIGF（统一可交换抽卡记录标准）
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UigfRecord {
+    pub gacha_type: i32,
+    pub item_name: String,
+    pub rarity: i32,
+    pub count: i32,
+    pub is_up: bool,
+    pub time: String,
+}
+
+/// 单个卡池的累计抽取汇总，随记录一并导出，避免导入时只能靠五星明细反推总抽数
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UigfPoolSummary {
+    pub gacha_type: i32,
+    pub total: i32,
+    pub pull_count: i32,
+    pub three_count: i32,
+    pub four_count: i32,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct UigfInfo {
+    pub export_app: String,
+    pub export_time: String,
+    pub version: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct UigfExport {
+    pub info: UigfInfo,
+    pub pools: Vec<UigfPoolSummary>,
+    pub list: Vec<UigfRecord>,
+}
+
+/// 将内存中的抽卡统计数据按 UIGF 风格导出为 JSON 文件
+pub fn export_to_file(gacha_statistics: &GachaStatistics, path: &Path) -> anyhow::Result<()> {
+    let mut pools = vec![];
+    let mut list = vec![];
+    for (card_pool_type, data) in gacha_statistics.iter() {
+        pools.push(UigfPoolSummary {
+            gacha_type: *card_pool_type,
+            total: data.total,
+            pull_count: data.pull_count,
+            three_count: data.three_count,
+            four_count: data.four_count,
+        });
+
+        for item in &data.detail {
+            list.push(UigfRecord {
+                gacha_type: *card_pool_type,
+                item_name: item.name.clone(),
+                rarity: 5,
+                count: item.count,
+                is_up: item.is_up,
+                time: item.time.clone(),
+            });
+        }
+    }
+
+    let export = UigfExport {
+        info: UigfInfo {
+            export_app: "wuthering-waves-gacha-record".to_string(),
+            export_time: chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            version: "v1.0".to_string(),
+        },
+        pools,
+        list,
+    };
+
+    let content = serde_json::to_string_pretty(&export)?;
+    fs::write(path, content)?;
+    Ok(())
+}
+
+/// 从 UIGF 风格的 JSON 文件中读取抽卡记录，重建为统计数据供图表展示
+pub fn import_from_file(path: &Path) -> anyhow::Result<GachaStatistics> {
+    let content = fs::read_to_string(path)?;
+    let export: UigfExport = serde_json::from_str(&content)?;
+
+    let mut map: HashMap<i32, GachaStatisticsData> = HashMap::new();
+    for pool in export.pools {
+        let data = map.entry(pool.gacha_type).or_insert_with(GachaStatisticsData::default);
+        data.total = pool.total;
+        data.pull_count = pool.pull_count;
+        data.three_count = pool.three_count;
+        data.four_count = pool.four_count;
+    }
+
+    for record in export.list {
+        let data = map.entry(record.gacha_type).or_insert_with(GachaStatisticsData::default);
+        data.five_count += record.count;
+        data.detail.push(GachaStatisticsDataItem {
+            name: record.item_name,
+            count: record.count,
+            is_up: record.is_up,
+            time: record.time,
+        });
+    }
+
+    Ok(GachaStatistics::from(map))
+}
@@ -1,4 +1,5 @@
 use std::cmp::min;
+use std::path::PathBuf;
 use std::sync::{Arc, mpsc};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::{Receiver, Sender};
@@ -8,10 +9,16 @@ use eframe::glow::Context;
 use crate::gacha_statistics;
 use egui::{CentralPanel, Color32, FontData, FontId, TextStyle, Vec2, Vec2b, Visuals};
 use egui::FontFamily::{Monospace, Proportional};
-use egui_plot::{Bar, BarChart, Corner, Legend, Plot};
+use egui_plot::{Bar, BarChart, Corner, Legend, Line, Plot, PlotPoints};
 use tracing::{error, info};
+use crate::core::auth_url::extract_authkey;
+use crate::core::banner::classify_up_items;
+use crate::core::gacha_analysis::{self, FiftyFiftyStats};
+use crate::core::log_scanner;
 use crate::core::message::{Message, MessageSender};
+use crate::core::probability::{model_for_card_pool_type, ProbabilityModel};
 use crate::core::statistics::{gacha_statistics_from_cache, GachaStatistics, GachaStatisticsDataItem};
+use crate::core::uigf;
 
 fn setup_custom_fonts(ctx: &egui::Context) {
     // Start with the default fonts (we will be adding to them rather than replacing them).
@@ -42,6 +49,12 @@ fn setup_custom_fonts(ctx: &egui::Context) {
 pub(crate) struct MainView {
     dark_mode: bool,
     update_flag_tx: Sender<bool>,
+    url_tx: Sender<String>,
+    url_input: String,
+    log_scan_tx: Sender<()>,
+    export_tx: Sender<(PathBuf, GachaStatistics)>,
+    import_tx: Sender<(PathBuf, GachaStatistics)>,
+    data_tx: Sender<GachaStatistics>,
     data_rx: Receiver<GachaStatistics>,
     message_rx: Receiver<Message>,
     message: Message,
@@ -55,6 +68,10 @@ impl MainView {
         setup_custom_fonts(&cc.egui_ctx);
 
         let (update_flag_tx, update_flag_rx) = mpsc::channel();
+        let (url_tx, url_rx) = mpsc::channel();
+        let (log_scan_tx, log_scan_rx) = mpsc::channel();
+        let (export_tx, export_rx) = mpsc::channel();
+        let (import_tx, import_rx) = mpsc::channel();
         let (data_tx, data_rx) = mpsc::channel();
         let (message_tx, message_rx) = mpsc::channel();
 
@@ -62,11 +79,17 @@ impl MainView {
 
         let on_exit_flag = Arc::new(AtomicBool::new(false));
 
-        start_data_flush_thread(Arc::clone(&on_exit_flag), update_flag_rx, data_tx, message_sender);
+        start_data_flush_thread(Arc::clone(&on_exit_flag), update_flag_rx, url_rx, log_scan_rx, export_rx, import_rx, data_tx.clone(), message_sender);
 
         Self {
             dark_mode: false,
             update_flag_tx,
+            url_tx,
+            url_input: String::new(),
+            log_scan_tx,
+            export_tx,
+            import_tx,
+            data_tx,
             data_rx,
             message_rx,
             message: Message::default(),
@@ -77,8 +100,31 @@ impl MainView {
     }
 }
 
+/// 拉取抽卡数据、标注 UP 物品并发布给 UI，三条刷新路径（粘贴链接、本地日志、常规刷新）共用
+async fn fetch_and_publish(message_sender: &MessageSender, data_tx: &Sender<GachaStatistics>, authkey: Option<&str>) {
+    match gacha_statistics(message_sender, authkey).await {
+        Ok(mut gacha_statistics_data) => {
+            classify_up_items(&mut gacha_statistics_data);
+            if let Ok(_) = data_tx.send(gacha_statistics_data) {
+                message_sender.success("获取完毕".to_string());
+                info!("刷新统计图");
+            } else {
+                error!("数据传输失败");
+            }
+        }
+        Err(err) => {
+            message_sender.failed(format!("抽卡数据统计失败，失败原因：{}", err));
+            error!("抽卡数据统计失败：{}", err);
+        }
+    }
+}
+
 fn start_data_flush_thread(on_exit_flag_clone: Arc<AtomicBool>,
                            update_flag_rx: Receiver<bool>,
+                           url_rx: Receiver<String>,
+                           log_scan_rx: Receiver<()>,
+                           export_rx: Receiver<(PathBuf, GachaStatistics)>,
+                           import_rx: Receiver<(PathBuf, GachaStatistics)>,
                            data_tx: Sender<GachaStatistics>,
                            message_sender: MessageSender) {
     tokio::spawn(async move {
@@ -88,6 +134,91 @@ fn start_data_flush_thread(on_exit_flag_clone: Arc<AtomicBool>,
                 info!("应用退出，停止后台线程");
                 break;
             }
+
+            // 用户粘贴了查询链接，优先校验并使用其中的 authkey 获取数据
+            if let Ok(url) = url_rx.try_recv() {
+                match extract_authkey(&url) {
+                    Some(authkey) => {
+                        message_sender.send("正在使用粘贴的链接获取数据...".to_string());
+                        fetch_and_publish(&message_sender, &data_tx, Some(authkey.as_str())).await;
+                    }
+                    None => {
+                        message_sender.failed("链接中未找到有效的 authkey，请重新复制抽卡记录链接".to_string());
+                    }
+                }
+                continue;
+            }
+
+            // 用户请求从本地日志中查找抽卡记录链接，文件读取放到阻塞线程池中执行，避免卡住 UI
+            if let Ok(()) = log_scan_rx.try_recv() {
+                message_sender.send("正在从本地日志中查找抽卡记录链接...".to_string());
+                match tokio::task::spawn_blocking(log_scanner::find_gacha_url_from_log).await {
+                    Ok(Ok(url)) => {
+                        match extract_authkey(&url) {
+                            Some(authkey) => {
+                                message_sender.send("正在使用查找到的链接获取数据...".to_string());
+                                fetch_and_publish(&message_sender, &data_tx, Some(authkey.as_str())).await;
+                            }
+                            None => {
+                                message_sender.failed("日志中未找到有效的 authkey".to_string());
+                            }
+                        }
+                    }
+                    Ok(Err(err)) => {
+                        message_sender.failed(format!("{}", err));
+                        error!("自动获取抽卡记录链接失败：{}", err);
+                    }
+                    Err(err) => {
+                        message_sender.failed("内部错误".to_string());
+                        error!("日志扫描任务失败：{}", err);
+                    }
+                }
+                continue;
+            }
+
+            // 导出记录涉及磁盘写入与 JSON 序列化，放到阻塞线程池中执行，避免卡住 UI
+            if let Ok((path, snapshot)) = export_rx.try_recv() {
+                message_sender.send("正在导出...".to_string());
+                match tokio::task::spawn_blocking(move || uigf::export_to_file(&snapshot, &path)).await {
+                    Ok(Ok(())) => message_sender.success("导出成功".to_string()),
+                    Ok(Err(err)) => {
+                        message_sender.failed(format!("导出失败：{}", err));
+                        error!("导出抽卡记录失败：{}", err);
+                    }
+                    Err(err) => {
+                        message_sender.failed("内部错误".to_string());
+                        error!("导出任务失败：{}", err);
+                    }
+                }
+                continue;
+            }
+
+            // 导入记录涉及磁盘读取与 JSON 解析，同样放到阻塞线程池中执行；
+            // 合并使用触发导入时的数据快照，避免持有跨线程的可变状态
+            if let Ok((path, snapshot)) = import_rx.try_recv() {
+                message_sender.send("正在导入...".to_string());
+                match tokio::task::spawn_blocking(move || uigf::import_from_file(&path)).await {
+                    Ok(Ok(imported)) => {
+                        let mut merged = snapshot;
+                        merged.merge(imported);
+                        if let Ok(_) = data_tx.send(merged) {
+                            message_sender.success("导入成功".to_string());
+                        } else {
+                            error!("数据传输失败");
+                        }
+                    }
+                    Ok(Err(err)) => {
+                        message_sender.failed(format!("导入失败：{}", err));
+                        error!("导入抽卡记录失败：{}", err);
+                    }
+                    Err(err) => {
+                        message_sender.failed("内部错误".to_string());
+                        error!("导入任务失败：{}", err);
+                    }
+                }
+                continue;
+            }
+
             if first_flag || update_flag_rx.recv_timeout(Duration::from_secs(1)).is_ok() {
                 message_sender.send("加载中...".to_string());
                 if first_flag {
@@ -111,20 +242,7 @@ fn start_data_flush_thread(on_exit_flag_clone: Arc<AtomicBool>,
                     }
                 }
 
-                match gacha_statistics(&message_sender).await {
-                    Ok(gacha_statistics_data) => {
-                        if let Ok(_) = data_tx.send(gacha_statistics_data) {
-                            message_sender.success("获取完毕".to_string());
-                            info!("刷新统计图");
-                        } else {
-                            error!("数据传输失败");
-                        }
-                    }
-                    Err(err) => {
-                        message_sender.failed(format!("抽卡数据统计失败，失败原因：{}", err));
-                        error!("抽卡数据统计失败：{}", err);
-                    }
-                }
+                fetch_and_publish(&message_sender, &data_tx, None).await;
             }
         }
     });
@@ -162,6 +280,29 @@ impl eframe::App for MainView {
                     let _ = &self.gacha_statistic_view_vec.clear();
                 }
 
+                let export_button = ui.button("导出记录");
+                if export_button.clicked() {
+                    if let Some(path) = rfd::FileDialog::new()
+                        .set_file_name("gacha_record.json")
+                        .save_file()
+                    {
+                        let _ = self.export_tx.send((path, self.gacha_statistics.clone()));
+                    }
+                }
+
+                let import_button = ui.button("导入记录");
+                if import_button.clicked() {
+                    if let Some(path) = rfd::FileDialog::new()
+                        .add_filter("json", &["json"])
+                        .pick_file()
+                    {
+                        // 导入用于合并跨设备记录，不能直接覆盖当前已加载的数据；
+                        // 合并发生在后台线程，这里只是把当前快照一并带过去
+                        let _ = self.import_tx.send((path, self.gacha_statistics.clone()));
+                        self.gacha_statistic_view_vec.clear();
+                    }
+                }
+
                 if let Ok(message) = self.message_rx.try_recv() {
                     self.message = message;
                 }
@@ -172,6 +313,24 @@ impl eframe::App for MainView {
                 }
             });
 
+            ui.horizontal(|ui| {
+                ui.label("抽卡记录链接：");
+                ui.text_edit_singleline(&mut self.url_input);
+                let fetch_by_url_button = ui.button("使用链接获取");
+                if fetch_by_url_button.clicked() {
+                    info!("使用粘贴的链接刷新数据...");
+                    let _ = self.url_tx.send(self.url_input.clone());
+                    self.gacha_statistic_view_vec.clear();
+                }
+
+                let auto_fetch_button = ui.button("从本地日志自动获取");
+                if auto_fetch_button.clicked() {
+                    info!("尝试从本地日志中获取抽卡记录链接...");
+                    let _ = self.log_scan_tx.send(());
+                    self.gacha_statistic_view_vec.clear();
+                }
+            });
+
             // 刷新统计图内容
             let _ = &self.create_bar_chart(&self.gacha_statistics.clone());
             let gacha_statistic_view_vec = &mut self.gacha_statistic_view_vec;
@@ -234,6 +393,38 @@ impl eframe::App for MainView {
                                                 ui.label(format!("{}[{}]", item.name, item.count));
                                             }
                                         });
+
+                                        let pulls_left = item.probability_model.pulls_until_guarantee(item.pull_count);
+                                        let future_chance = item.probability_model.chance_within_next_pulls(item.pull_count, 10);
+                                        let percents = item.probability_model.probability_percents();
+                                        let points: PlotPoints = percents.iter().enumerate()
+                                            .map(|(pity, percent)| [pity as f64, *percent])
+                                            .collect();
+                                        Plot::new(format!("{}_probability", item.card_pool_type))
+                                            .allow_zoom(false)
+                                            .allow_drag(false)
+                                            .allow_scroll(false)
+                                            .allow_boxed_zoom(false)
+                                            .show_axes(Vec2b::from([true, true]))
+                                            .show_grid(false)
+                                            .width(285.0)
+                                            .height(80.0)
+                                            .set_margin_fraction(Vec2::from([0.2, 0.2]))
+                                            .show(ui, |plot_ui| {
+                                                plot_ui.line(Line::new(points).name("出金概率"));
+                                            });
+                                        ui.label(format!("距离保底还需 {} 抽，未来 10 抽出金概率 {:.1}%", pulls_left, future_chance));
+
+                                        if item.card_pool_type == 1 || item.card_pool_type == 2 {
+                                            let stats = &item.fifty_fifty_stats;
+                                            if stats.has_up_data {
+                                                let guarantee_state = if stats.is_guaranteed { "已歪，下次五星必中UP" } else { "未歪，下次五星50/50" };
+                                                ui.label(format!("UP 50/50：{}胜{}负，{}，平均每{:.1}抽出一个五星",
+                                                                  stats.won, stats.lost, guarantee_state, stats.average_pulls_per_five_star));
+                                            } else {
+                                                ui.label("暂无当期 UP 物品名单，无法统计 50/50");
+                                            }
+                                        }
                                     });
                                 }
                             });
@@ -256,6 +447,8 @@ struct GachaStatisticsView {
     pull_count: i32,
     bar_chart_vec: Vec<BarChart>,
     detail: Vec<GachaStatisticsDataItem>,
+    probability_model: ProbabilityModel,
+    fifty_fifty_stats: FiftyFiftyStats,
 }
 
 impl MainView {
@@ -297,6 +490,8 @@ impl MainView {
                     pull_count: gacha_statistics_data.pull_count,
                     bar_chart_vec,
                     detail: gacha_statistics_data.detail.clone(),
+                    probability_model: model_for_card_pool_type(*card_pool_type),
+                    fifty_fifty_stats: gacha_analysis::analyze(*card_pool_type, gacha_statistics_data),
                 };
 
                 gacha_statistic_view_vec.push(gacha_statistic_view);